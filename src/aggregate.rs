@@ -0,0 +1,36 @@
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::event::DomainEvent;
+
+/// An aggregate is the consistency boundary for a set of business rules: it handles commands by
+/// validating them against its current state, producing events if accepted, and applies events
+/// (both newly produced and replayed from the event store) to update that state.
+pub trait Aggregate: Default + Serialize + DeserializeOwned + Sync + Send {
+    /// The command type this aggregate accepts.
+    type Command: Send + Sync;
+    /// The event type this aggregate produces and applies.
+    type Event: DomainEvent;
+    /// The error returned when a command is rejected by a business rule.
+    type Error: std::error::Error + Send + Sync;
+    /// External services the aggregate needs to handle a command (e.g. a uniqueness check
+    /// against a read model), injected by the caller.
+    type Services: Send + Sync;
+
+    /// A unique identifier for this aggregate type, used to partition its event stream from
+    /// other aggregate types in the event store.
+    fn aggregate_type() -> String;
+
+    /// Validates `command` against the current aggregate state and `service`, returning the
+    /// events to apply if it is accepted.
+    fn handle(
+        &self,
+        command: Self::Command,
+        service: &Self::Services,
+    ) -> impl Future<Output = Result<Vec<Self::Event>, Self::Error>> + Send;
+
+    /// Updates aggregate state to reflect a previously committed (or just-produced) event.
+    fn apply(&mut self, event: Self::Event);
+}
@@ -0,0 +1,325 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::aggregate::Aggregate;
+use crate::error::AggregateError;
+use crate::store::EventStore;
+use crate::with_trace;
+
+/// Configuration for the bounded retry loop `CqrsFramework::execute` runs when a command fails
+/// with a transient error (see `AggregateError::is_transient`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryConfig {
+    /// The maximum number of attempts to make, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// The base delay used to compute exponential backoff between attempts.
+    pub base_delay: Duration,
+    /// The maximum delay between attempts, capping the exponential backoff.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// The backoff delay to wait after attempt number `attempt` (1-indexed) fails, before the
+    /// next attempt: exponential
+    /// backoff off `base_delay`, capped at `max_delay`, with up to 50% jitter so that multiple
+    /// callers retrying the same conflict don't all wake up at once.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let exponential = self.base_delay.saturating_mul(1u32 << exponent);
+        let capped = exponential.min(self.max_delay);
+        let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+        capped.mul_f64(jitter)
+    }
+}
+
+/// Coordinates loading an aggregate, handling a command against it, and committing the
+/// resulting events, retrying on transient failures.
+pub struct CqrsFramework<A, ES>
+where
+    A: Aggregate,
+    ES: EventStore<A>,
+{
+    store: ES,
+    services: A::Services,
+    retry: RetryConfig,
+}
+
+impl<A, ES> CqrsFramework<A, ES>
+where
+    A: Aggregate,
+    ES: EventStore<A>,
+{
+    /// Constructs a new framework instance around `store`, using `RetryConfig::default()`.
+    pub fn new(store: ES, services: A::Services) -> Self {
+        Self::new_with_retry(store, services, RetryConfig::default())
+    }
+
+    /// Constructs a new framework instance around `store` with a custom retry policy.
+    pub fn new_with_retry(store: ES, services: A::Services, retry: RetryConfig) -> Self {
+        Self {
+            store,
+            services,
+            retry,
+        }
+    }
+
+    /// Loads the aggregate identified by `aggregate_id`, hands `command` to it, and commits the
+    /// resulting events.
+    ///
+    /// If the attempt fails with a transient error (`AggregateError::is_transient`), the
+    /// aggregate is reloaded — so the retry observes events committed by whichever writer it
+    /// conflicted with — and `command` is re-handled against that fresh state, up to
+    /// `RetryConfig::max_attempts` times with exponential backoff and jitter between attempts.
+    /// `AggregateError::UserError` is never retried, since the business rule that rejected it
+    /// won't pass just because time passed. If every attempt is exhausted, the last error
+    /// encountered is returned.
+    pub async fn execute(
+        &self,
+        aggregate_id: &str,
+        command: A::Command,
+    ) -> Result<(), AggregateError<A::Error>>
+    where
+        A::Command: Clone,
+    {
+        let mut attempt = 1;
+        loop {
+            match self.try_execute(aggregate_id, command.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(err) if attempt < self.retry.max_attempts && err.is_transient() => {
+                    tokio::time::sleep(self.retry.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    async fn try_execute(
+        &self,
+        aggregate_id: &str,
+        command: A::Command,
+    ) -> Result<(), AggregateError<A::Error>> {
+        let context = self
+            .store
+            .load_aggregate(aggregate_id)
+            .await
+            .map_err(|err| with_trace!(AggregateError::from(err)))?;
+        let events = context
+            .aggregate
+            .handle(command, &self.services)
+            .await
+            .map_err(AggregateError::UserError)?;
+        self.store
+            .commit(events, context)
+            .await
+            .map_err(|err| with_trace!(AggregateError::from(err)))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::event::{DomainEvent, EventEnvelope};
+    use crate::persist::PersistenceError;
+    use crate::store::AggregateContext;
+
+    #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+    struct TestAggregate {
+        applied: usize,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestEvent;
+
+    impl DomainEvent for TestEvent {
+        fn event_type(&self) -> String {
+            "TestEvent".to_string()
+        }
+
+        fn event_version(&self) -> String {
+            "1.0".to_string()
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct TestError;
+
+    impl std::fmt::Display for TestError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "rejected")
+        }
+    }
+
+    impl std::error::Error for TestError {}
+
+    impl Aggregate for TestAggregate {
+        type Command = ();
+        type Event = TestEvent;
+        type Error = TestError;
+        type Services = ();
+
+        fn aggregate_type() -> String {
+            "test".to_string()
+        }
+
+        async fn handle(&self, _command: (), _service: &()) -> Result<Vec<TestEvent>, TestError> {
+            Ok(vec![TestEvent])
+        }
+
+        fn apply(&mut self, _event: TestEvent) {
+            self.applied += 1;
+        }
+    }
+
+    struct FlakyStore {
+        remaining_conflicts: Mutex<usize>,
+        commits: AtomicUsize,
+    }
+
+    impl EventStore<TestAggregate> for FlakyStore {
+        async fn load_aggregate(
+            &self,
+            aggregate_id: &str,
+        ) -> Result<AggregateContext<TestAggregate>, PersistenceError> {
+            Ok(AggregateContext {
+                aggregate_id: aggregate_id.to_string(),
+                aggregate: TestAggregate::default(),
+                current_sequence: 0,
+            })
+        }
+
+        async fn commit(
+            &self,
+            events: Vec<TestEvent>,
+            context: AggregateContext<TestAggregate>,
+        ) -> Result<Vec<EventEnvelope<TestAggregate>>, PersistenceError> {
+            let mut remaining = self.remaining_conflicts.lock().unwrap();
+            if *remaining > 0 {
+                *remaining -= 1;
+                return Err(PersistenceError::OptimisticLock(None));
+            }
+            self.commits.fetch_add(1, Ordering::SeqCst);
+            Ok(events
+                .into_iter()
+                .enumerate()
+                .map(|(i, payload)| EventEnvelope {
+                    aggregate_id: context.aggregate_id.clone(),
+                    sequence: context.current_sequence + i + 1,
+                    payload,
+                })
+                .collect())
+        }
+    }
+
+    fn test_retry_config() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_a_transient_conflict_until_it_succeeds() {
+        let store = FlakyStore {
+            remaining_conflicts: Mutex::new(1),
+            commits: AtomicUsize::new(0),
+        };
+        let cqrs = CqrsFramework::new_with_retry(store, (), test_retry_config());
+
+        let result = cqrs.execute("test-1", ()).await;
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(cqrs.store.commits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn exhausts_attempts_and_surfaces_the_last_error() {
+        let store = FlakyStore {
+            remaining_conflicts: Mutex::new(5),
+            commits: AtomicUsize::new(0),
+        };
+        let cqrs = CqrsFramework::new_with_retry(store, (), test_retry_config());
+
+        let result = cqrs.execute("test-1", ()).await;
+
+        assert_eq!(result, Err(AggregateError::AggregateConflict));
+        assert_eq!(cqrs.store.commits.load(Ordering::SeqCst), 0);
+    }
+
+    #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+    struct RejectingAggregate;
+
+    impl Aggregate for RejectingAggregate {
+        type Command = ();
+        type Event = TestEvent;
+        type Error = TestError;
+        type Services = ();
+
+        fn aggregate_type() -> String {
+            "rejecting".to_string()
+        }
+
+        async fn handle(&self, _command: (), _service: &()) -> Result<Vec<TestEvent>, TestError> {
+            Err(TestError)
+        }
+
+        fn apply(&mut self, _event: TestEvent) {}
+    }
+
+    struct PanicsOnCommitStore {
+        loads: AtomicUsize,
+    }
+
+    impl EventStore<RejectingAggregate> for PanicsOnCommitStore {
+        async fn load_aggregate(
+            &self,
+            aggregate_id: &str,
+        ) -> Result<AggregateContext<RejectingAggregate>, PersistenceError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            Ok(AggregateContext {
+                aggregate_id: aggregate_id.to_string(),
+                aggregate: RejectingAggregate,
+                current_sequence: 0,
+            })
+        }
+
+        async fn commit(
+            &self,
+            _events: Vec<TestEvent>,
+            _context: AggregateContext<RejectingAggregate>,
+        ) -> Result<Vec<EventEnvelope<RejectingAggregate>>, PersistenceError> {
+            panic!("a rejected command should never reach commit");
+        }
+    }
+
+    #[tokio::test]
+    async fn never_retries_a_user_error() {
+        let store = PanicsOnCommitStore {
+            loads: AtomicUsize::new(0),
+        };
+        let cqrs = CqrsFramework::new_with_retry(store, (), test_retry_config());
+
+        let result = cqrs.execute("test-1", ()).await;
+
+        assert_eq!(result, Err(AggregateError::UserError(TestError)));
+        assert_eq!(cqrs.store.loads.load(Ordering::SeqCst), 1);
+    }
+}
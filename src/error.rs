@@ -28,7 +28,122 @@ pub enum AggregateError<T: std::error::Error> {
     /// In a Restful application this usually translates to a 500 or 503 response status.
     ///
     /// In a production system this may indicate a serious error and should be investigated.
-    TechnicalError(String),
+    TechnicalError(TechnicalError),
+}
+
+/// A single frame recording where a [`TechnicalError`] was observed as it propagated up
+/// through a layer of the application, e.g. from the event store into the repository and then
+/// into the command handler. Pushed by the [`with_trace!`] macro.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    /// The file the error passed through.
+    pub file: &'static str,
+    /// The line within `file`.
+    pub line: u32,
+    /// The function within `file`.
+    pub function: &'static str,
+}
+
+/// The payload carried by `AggregateError::TechnicalError`.
+///
+/// Unlike a flat `String`, this keeps the originating error as `source` (so `std::error::Error`
+/// callers can walk the real cause rather than a collapsed message) and a `trace` of the layers
+/// the error passed through on its way up, so an operator investigating a failure can see the
+/// full path from the command handler down to the persistence layer.
+#[derive(Debug)]
+pub struct TechnicalError {
+    /// A human-readable summary of the error.
+    pub message: String,
+    /// The underlying cause, if one is available.
+    pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    /// The frames this error has passed through, oldest first.
+    pub trace: Vec<Trace>,
+    /// Whether simply retrying the command is expected to succeed.
+    ///
+    /// This is stamped explicitly by whoever constructs the error (e.g.
+    /// `From<PersistenceError> for AggregateError<T>` sets it from `PersistenceError::is_retryable`)
+    /// rather than inferred by downcasting `source`, since an intermediate layer may wrap the
+    /// original cause in its own error type before attaching it here.
+    pub transient: bool,
+}
+
+impl TechnicalError {
+    /// Constructs a new `TechnicalError` with no cause or trace frames yet attached, defaulting
+    /// to non-transient.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+            trace: Vec::new(),
+            transient: false,
+        }
+    }
+
+    /// Attaches `source` as the underlying cause of this error.
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    /// Sets whether this error is transient, i.e., whether simply retrying the command is
+    /// expected to succeed.
+    pub fn with_transient(mut self, transient: bool) -> Self {
+        self.transient = transient;
+        self
+    }
+}
+
+impl PartialEq for TechnicalError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+            && self.trace == other.trace
+            && self.transient == other.transient
+            && match (&self.source, &other.source) {
+                (None, None) => true,
+                (Some(this), Some(other)) => this.to_string() == other.to_string(),
+                _ => false,
+            }
+    }
+}
+
+impl fmt::Display for TechnicalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for TechnicalError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn error::Error + 'static))
+    }
+}
+
+/// Captures the current `file!()`, `line!()` and enclosing function name as a [`Trace`] frame
+/// and pushes it onto a `TechnicalError`, so that each layer an error bubbles through leaves a
+/// breadcrumb behind. Has no effect on other `AggregateError` variants.
+///
+/// ```
+/// # use cqrs_es::{AggregateError, TechnicalError, UserErrorPayload, with_trace};
+/// fn load() -> Result<(), AggregateError<UserErrorPayload>> {
+///     Err(AggregateError::TechnicalError(TechnicalError::new("connection reset")))
+/// }
+/// let err = with_trace!(load().unwrap_err());
+/// ```
+#[macro_export]
+macro_rules! with_trace {
+    ($err:expr) => {{
+        let mut err = $err;
+        if let $crate::AggregateError::TechnicalError(ref mut technical_error) = err {
+            technical_error.trace.push($crate::Trace {
+                file: file!(),
+                line: line!(),
+                function: $crate::stdext::function_name!(),
+            });
+        }
+        err
+    }};
 }
 
 /// Payload for an `AggregateError::UserError`, somewhat modeled on the errors produced by the
@@ -40,16 +155,37 @@ pub struct UserErrorPayload {
     pub code: Option<String>,
     /// An optional message describing the error, meant to be returned to the user.
     pub message: Option<String>,
+    /// An optional translation key that a `MessageResolver` can use, together with `params`, to
+    /// render `message` in a caller-chosen locale.
+    pub message_key: Option<String>,
     /// Optional additional parameters for adding additional context to the error.
     pub params: Option<HashMap<String, String>>,
 }
 
-impl<T: std::error::Error> error::Error for AggregateError<T> {}
+/// Resolves a `message_key` (and its interpolation `params`) from `UserErrorPayload` into a
+/// localized message for a given locale, so RESTful callers can return translated error
+/// responses while still exposing the machine-readable `code`/`params` to clients that prefer
+/// to localize themselves.
+pub trait MessageResolver {
+    /// Looks up `key` for `locale` and renders it using `params`, returning `None` if there is
+    /// no translation for that key/locale combination.
+    fn resolve(&self, key: &str, params: &HashMap<String, String>, locale: &str)
+        -> Option<String>;
+}
+
+impl<T: std::error::Error> error::Error for AggregateError<T> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AggregateError::TechnicalError(technical_error) => Some(technical_error),
+            AggregateError::UserError(_) | AggregateError::AggregateConflict => None,
+        }
+    }
+}
 
 impl<T: std::error::Error> fmt::Display for AggregateError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            AggregateError::TechnicalError(message) => write!(f, "{}", message),
+            AggregateError::TechnicalError(technical_error) => write!(f, "{}", technical_error),
             AggregateError::UserError(message) => write!(f, "{}", message),
             AggregateError::AggregateConflict => write!(f, "aggregate conflict"),
         }
@@ -79,6 +215,7 @@ impl AggregateError<UserErrorPayload> {
         AggregateError::UserError(UserErrorPayload {
             code: None,
             message: Some(msg.to_string()),
+            message_key: None,
             params: None,
         })
     }
@@ -92,28 +229,222 @@ impl AggregateError<UserErrorPayload> {
         AggregateError::UserError(UserErrorPayload {
             code: Some(code.to_string()),
             message: Some(msg.to_string()),
+            message_key: None,
             params: None,
         })
     }
+
+    /// Returns a copy of this error with `UserErrorPayload::message` resolved from
+    /// `message_key` and `params` via `resolver`, for the requested `locale`.
+    ///
+    /// Falls back to the existing `message` when there is no `message_key` set, or when
+    /// `resolver` has no translation for it. Has no effect on `AggregateConflict` or
+    /// `TechnicalError`, since only `UserError` carries a `UserErrorPayload`.
+    pub fn localize(self, resolver: &dyn MessageResolver, locale: &str) -> Self {
+        match self {
+            AggregateError::UserError(payload) => {
+                let UserErrorPayload {
+                    code,
+                    message,
+                    message_key,
+                    params,
+                } = payload;
+                let empty_params = HashMap::new();
+                let resolved_message = message_key
+                    .as_deref()
+                    .and_then(|key| {
+                        resolver.resolve(key, params.as_ref().unwrap_or(&empty_params), locale)
+                    })
+                    .or(message);
+                AggregateError::UserError(UserErrorPayload {
+                    code,
+                    message: resolved_message,
+                    message_key,
+                    params,
+                })
+            }
+            other => other,
+        }
+    }
 }
 
 impl<T: std::error::Error> AggregateError<T> {
-    fn new_technical_error(msg: &str) -> Self {
-        AggregateError::TechnicalError(msg.to_string())
+    /// Indicates whether this error is transient, i.e., whether simply retrying the command
+    /// (after reloading the aggregate so the retry observes any newly committed events) is
+    /// likely to succeed.
+    ///
+    /// An `AggregateConflict` is always transient. A `UserError` is never transient, since
+    /// retrying without the user changing their request will fail the same way every time. A
+    /// `TechnicalError` is transient exactly when it was constructed as such, e.g. by
+    /// `From<PersistenceError> for AggregateError<T>` when the wrapped persistence error is
+    /// itself tagged as retryable (a connection-pool failure; an optimistic-lock conflict
+    /// becomes an `AggregateConflict` instead).
+    pub fn is_transient(&self) -> bool {
+        match self {
+            AggregateError::AggregateConflict => true,
+            AggregateError::UserError(_) => false,
+            AggregateError::TechnicalError(technical_error) => technical_error.transient,
+        }
     }
 }
 
 impl<T: std::error::Error> From<serde_json::error::Error> for AggregateError<T> {
     fn from(err: serde_json::error::Error) -> Self {
         match err.classify() {
-            serde_json::error::Category::Syntax => {
-                AggregateError::new_technical_error("invalid json")
-            }
+            serde_json::error::Category::Syntax => AggregateError::TechnicalError(
+                TechnicalError::new("invalid json").with_source(err),
+            ),
             serde_json::error::Category::Io
             | serde_json::error::Category::Data
             | serde_json::error::Category::Eof => {
-                AggregateError::new_technical_error(&err.to_string())
+                let message = err.to_string();
+                AggregateError::TechnicalError(TechnicalError::new(message).with_source(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct TestUserError;
+
+    impl fmt::Display for TestUserError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "test user error")
+        }
+    }
+
+    impl error::Error for TestUserError {}
+
+    #[test]
+    fn aggregate_conflict_is_always_transient() {
+        let err: AggregateError<TestUserError> = AggregateError::AggregateConflict;
+        assert!(err.is_transient());
+    }
+
+    #[test]
+    fn user_error_is_never_transient() {
+        let err = AggregateError::UserError(TestUserError);
+        assert!(!err.is_transient());
+    }
+
+    #[test]
+    fn technical_error_is_transient_exactly_when_stamped_as_such() {
+        let transient: AggregateError<TestUserError> =
+            AggregateError::TechnicalError(TechnicalError::new("retry me").with_transient(true));
+        assert!(transient.is_transient());
+
+        let not_transient: AggregateError<TestUserError> =
+            AggregateError::TechnicalError(TechnicalError::new("do not retry"));
+        assert!(!not_transient.is_transient());
+    }
+
+    #[test]
+    fn with_trace_pushes_a_frame_onto_a_technical_error() {
+        let err: AggregateError<TestUserError> =
+            AggregateError::TechnicalError(TechnicalError::new("connection reset"));
+
+        let err = with_trace!(err);
+
+        match err {
+            AggregateError::TechnicalError(technical_error) => {
+                assert_eq!(technical_error.trace.len(), 1);
+                assert_eq!(technical_error.trace[0].file, file!());
+                assert!(technical_error.trace[0]
+                    .function
+                    .ends_with("with_trace_pushes_a_frame_onto_a_technical_error"));
+            }
+            other => panic!("expected TechnicalError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn with_trace_has_no_effect_on_other_variants() {
+        let err = with_trace!(AggregateError::<TestUserError>::AggregateConflict);
+        assert_eq!(err, AggregateError::AggregateConflict);
+    }
+
+    struct StaticResolver;
+
+    impl MessageResolver for StaticResolver {
+        fn resolve(
+            &self,
+            key: &str,
+            params: &HashMap<String, String>,
+            locale: &str,
+        ) -> Option<String> {
+            match (key, locale) {
+                ("user.exists", "fr") => {
+                    let name = params.get("name").cloned().unwrap_or_default();
+                    Some(format!("{} existe déjà", name))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn localize_resolves_message_from_key_and_params() {
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), "alice".to_string());
+        let err = AggregateError::UserError(UserErrorPayload {
+            code: Some("USER_EXISTS".to_string()),
+            message: Some("user already exists".to_string()),
+            message_key: Some("user.exists".to_string()),
+            params: Some(params),
+        });
+
+        let localized = err.localize(&StaticResolver, "fr");
+
+        match localized {
+            AggregateError::UserError(payload) => {
+                assert_eq!(payload.message.as_deref(), Some("alice existe déjà"));
+                assert_eq!(payload.code.as_deref(), Some("USER_EXISTS"));
             }
+            other => panic!("expected UserError, got {:?}", other),
         }
     }
+
+    #[test]
+    fn localize_falls_back_to_message_when_no_message_key() {
+        let err = AggregateError::new_user_error("user already exists");
+
+        let localized = err.localize(&StaticResolver, "fr");
+
+        match localized {
+            AggregateError::UserError(payload) => {
+                assert_eq!(payload.message.as_deref(), Some("user already exists"));
+            }
+            other => panic!("expected UserError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn localize_falls_back_to_message_when_resolver_has_no_translation() {
+        let err = AggregateError::UserError(UserErrorPayload {
+            code: None,
+            message: Some("user already exists".to_string()),
+            message_key: Some("unknown.key".to_string()),
+            params: None,
+        });
+
+        let localized = err.localize(&StaticResolver, "fr");
+
+        match localized {
+            AggregateError::UserError(payload) => {
+                assert_eq!(payload.message.as_deref(), Some("user already exists"));
+            }
+            other => panic!("expected UserError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn localize_has_no_effect_on_other_variants() {
+        let err: AggregateError<UserErrorPayload> = AggregateError::AggregateConflict;
+        let localized = err.localize(&StaticResolver, "fr");
+        assert_eq!(localized, AggregateError::AggregateConflict);
+    }
 }
\ No newline at end of file
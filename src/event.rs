@@ -0,0 +1,25 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::aggregate::Aggregate;
+
+/// A domain event produced by an aggregate in response to a command, and later replayed by an
+/// `EventStore` to rebuild aggregate state.
+pub trait DomainEvent: Serialize + DeserializeOwned + Clone + PartialEq + Send + Sync {
+    /// A unique identifier for this event type, used when persisting and upcasting events.
+    fn event_type(&self) -> String;
+    /// The semantic version of this event's schema.
+    fn event_version(&self) -> String;
+}
+
+/// A single committed event for an aggregate instance, along with its position in that
+/// instance's event stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventEnvelope<A: Aggregate> {
+    /// The id of the aggregate instance this event belongs to.
+    pub aggregate_id: String,
+    /// This event's 1-indexed position in the aggregate's event stream.
+    pub sequence: usize,
+    /// The event itself.
+    pub payload: A::Event,
+}
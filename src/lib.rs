@@ -0,0 +1,22 @@
+//! # cqrs-es
+//!
+//! A lightweight, fully asynchronous CQRS/event-sourcing framework.
+
+pub use crate::aggregate::Aggregate;
+pub use crate::cqrs::{CqrsFramework, RetryConfig};
+pub use crate::error::{AggregateError, MessageResolver, TechnicalError, Trace, UserErrorPayload};
+pub use crate::event::{DomainEvent, EventEnvelope};
+pub use crate::store::{AggregateContext, EventStore};
+
+// Re-exported so `with_trace!`, usable from any downstream crate, can refer to
+// `$crate::stdext::function_name!()` rather than a bare `stdext::function_name!()` that would
+// resolve against the caller's own dependencies instead of ours.
+#[doc(hidden)]
+pub use stdext;
+
+mod aggregate;
+mod cqrs;
+mod error;
+mod event;
+pub mod persist;
+mod store;
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::{with_trace, AggregateError, TechnicalError};
+
+/// An error generated by a persistence layer when loading or committing events or
+/// materialized views, split into explicit kinds so callers can tell, for example, an
+/// optimistic-lock conflict from a connection failure instead of matching on a generic error,
+/// similar in spirit to the typed database errors exposed by [`sqlx::Error`].
+///
+/// [`sqlx::Error`]: https://docs.rs/sqlx/latest/sqlx/enum.Error.html
+#[derive(Debug)]
+pub enum PersistenceError {
+    /// Another writer committed events for the same aggregate instance first. Reloading the
+    /// aggregate and retrying the command is expected to succeed.
+    OptimisticLock(Option<String>),
+    /// The connection pool could not supply a connection, or the connection was lost mid-operation.
+    ConnectionPool(Option<String>),
+    /// A stored event or snapshot could not be deserialized back into its target type.
+    Deserialization(Box<dyn std::error::Error + Send + Sync>),
+    /// Any other persistence failure that doesn't fit a more specific kind.
+    Unknown(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl PersistenceError {
+    /// Indicates whether retrying the failed operation (after reloading aggregate state) is
+    /// likely to succeed, e.g. an optimistic-lock conflict or a transient connection failure.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            PersistenceError::OptimisticLock(_) | PersistenceError::ConnectionPool(_)
+        )
+    }
+}
+
+impl std::error::Error for PersistenceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistenceError::Deserialization(err) | PersistenceError::Unknown(err) => {
+                Some(err.as_ref())
+            }
+            PersistenceError::OptimisticLock(_) | PersistenceError::ConnectionPool(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistenceError::OptimisticLock(context) => match context {
+                Some(context) => write!(f, "optimistic lock conflict: {}", context),
+                None => write!(f, "optimistic lock conflict"),
+            },
+            PersistenceError::ConnectionPool(context) => match context {
+                Some(context) => write!(f, "connection pool error: {}", context),
+                None => write!(f, "connection pool error"),
+            },
+            PersistenceError::Deserialization(err) => write!(f, "deserialization error: {}", err),
+            PersistenceError::Unknown(err) => write!(f, "unknown persistence error: {}", err),
+        }
+    }
+}
+
+/// Translates a `PersistenceError` into the `AggregateError` it should surface as, for use by
+/// `PersistedEventStore`/`PersistedSnapshotStore` and repository crates (postgres-es, mysql-es,
+/// dynamo-es) implementing `PersistedEventRepository`.
+///
+/// `OptimisticLock` becomes an `AggregateConflict`, since that's exactly the case optimistic
+/// locking exists to detect. Every other kind becomes a `TechnicalError` carrying this error as
+/// its `source`, with `transient` stamped from `is_retryable` right here at conversion time —
+/// rather than left for `AggregateError::is_transient` to rediscover by downcasting `source`,
+/// which would miss cases where a repository crate wraps this error in its own connector error
+/// before attaching it. A `with_trace!` frame is left here too, marking the event store as the
+/// point the error entered the framework.
+impl<T: std::error::Error> From<PersistenceError> for AggregateError<T> {
+    fn from(err: PersistenceError) -> Self {
+        match err {
+            PersistenceError::OptimisticLock(_) => AggregateError::AggregateConflict,
+            PersistenceError::ConnectionPool(_)
+            | PersistenceError::Deserialization(_)
+            | PersistenceError::Unknown(_) => {
+                let transient = err.is_retryable();
+                let aggregate_error = AggregateError::TechnicalError(
+                    TechnicalError::new(err.to_string())
+                        .with_transient(transient)
+                        .with_source(err),
+                );
+                with_trace!(aggregate_error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct TestUserError;
+
+    impl fmt::Display for TestUserError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "test user error")
+        }
+    }
+
+    impl std::error::Error for TestUserError {}
+
+    #[derive(Debug)]
+    struct StubCause;
+
+    impl fmt::Display for StubCause {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "stub cause")
+        }
+    }
+
+    impl std::error::Error for StubCause {}
+
+    #[test]
+    fn optimistic_lock_and_connection_pool_are_retryable() {
+        assert!(PersistenceError::OptimisticLock(None).is_retryable());
+        assert!(PersistenceError::ConnectionPool(None).is_retryable());
+    }
+
+    #[test]
+    fn deserialization_and_unknown_are_not_retryable() {
+        assert!(!PersistenceError::Deserialization(Box::new(StubCause)).is_retryable());
+        assert!(!PersistenceError::Unknown(Box::new(StubCause)).is_retryable());
+    }
+
+    #[test]
+    fn optimistic_lock_maps_to_aggregate_conflict() {
+        let err: AggregateError<TestUserError> =
+            PersistenceError::OptimisticLock(Some("aggregate-1".to_string())).into();
+        assert_eq!(err, AggregateError::AggregateConflict);
+    }
+
+    #[test]
+    fn connection_pool_maps_to_a_transient_technical_error() {
+        let err: AggregateError<TestUserError> = PersistenceError::ConnectionPool(None).into();
+        match err {
+            AggregateError::TechnicalError(technical_error) => {
+                assert!(technical_error.transient);
+            }
+            other => panic!("expected TechnicalError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserialization_maps_to_a_non_transient_technical_error() {
+        let err: AggregateError<TestUserError> =
+            PersistenceError::Deserialization(Box::new(StubCause)).into();
+        match err {
+            AggregateError::TechnicalError(technical_error) => {
+                assert!(!technical_error.transient);
+            }
+            other => panic!("expected TechnicalError, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::future::Future;
+
+use crate::aggregate::Aggregate;
+use crate::event::EventEnvelope;
+use crate::persist::PersistenceError;
+
+/// An aggregate as loaded from an `EventStore`: its rebuilt state plus the sequence number of
+/// the last committed event, used for optimistic concurrency when committing new events.
+pub struct AggregateContext<A: Aggregate> {
+    /// The id of the aggregate instance.
+    pub aggregate_id: String,
+    /// The aggregate's state after replaying all committed events.
+    pub aggregate: A,
+    /// The sequence number of the last committed event that was replayed. `0` for an aggregate
+    /// instance with no committed events yet.
+    pub current_sequence: usize,
+}
+
+/// Loads and commits the events for an aggregate instance.
+pub trait EventStore<A: Aggregate>: Send + Sync {
+    /// Loads and replays all committed events for `aggregate_id`, returning the rebuilt
+    /// aggregate and its current sequence. An aggregate with no committed events yet is
+    /// returned as `A::default()` at sequence `0`.
+    fn load_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> impl Future<Output = Result<AggregateContext<A>, PersistenceError>> + Send;
+
+    /// Commits `events` for the aggregate instance described by `context`. Fails with
+    /// `PersistenceError::OptimisticLock` if `context.current_sequence` no longer matches what's
+    /// stored, i.e. another writer committed events for this instance first.
+    fn commit(
+        &self,
+        events: Vec<A::Event>,
+        context: AggregateContext<A>,
+    ) -> impl Future<Output = Result<Vec<EventEnvelope<A>>, PersistenceError>> + Send;
+}